@@ -0,0 +1,41 @@
+/// Minimal case conversion for translating Rust identifiers into whatever
+/// Lua-facing naming convention a consumer asked for via
+/// `#[mlua_magic(rename_all = "...")]`.
+///
+/// Words are split only at underscores, so a PascalCase identifier such as an
+/// enum variant (`VariantA`) counts as a single word.
+///
+/// Unrecognized styles leave the input unchanged; no macro validates the
+/// `rename_all` value, so a misspelled style is not reported.
+pub(crate) fn convert_case(input: &str, style: &str) -> String {
+	match style {
+		"camelCase" => to_camel_case(input, false),
+		"PascalCase" => to_camel_case(input, true),
+		"snake_case" => input.to_string(),
+		"SCREAMING_SNAKE_CASE" => input.to_uppercase(),
+		"kebab-case" => input.replace('_', "-"),
+		_ => input.to_string(),
+	}
+}
+
+fn to_camel_case(input: &str, capitalize_first: bool) -> String {
+	let mut output = String::with_capacity(input.len());
+
+	for (index, word) in input.split('_').enumerate() {
+		if word.is_empty() {
+			continue;
+		};
+
+		let mut chars = word.chars();
+		if let Some(first) = chars.next() {
+			if index == 0 && !capitalize_first {
+				output.extend(first.to_lowercase());
+			} else {
+				output.extend(first.to_uppercase());
+			};
+			output.push_str(chars.as_str());
+		};
+	}
+
+	return output;
+}
@@ -1,3 +1,4 @@
+mod case;
 mod compile;
 
 extern crate proc_macro;
@@ -7,15 +8,82 @@ use ::proc_macro::TokenStream;
 use ::proc_macro2;
 use ::proc_macro2::{Ident, };
 
-use ::quote::quote;
+use ::quote::{format_ident, quote};
 
 use ::syn::{
 	parse_macro_input,
+	punctuated::Punctuated,
+	DeriveInput,
 	Fields,
 	ImplItem, ItemEnum,
 	Pat,
+	Token,
 };
 
+/// Parsed contents of an inert `#[mlua_magic(...)]` attribute.
+///
+/// This lets users attach per-item overrides (e.g. marking a field
+/// `readonly`, or renaming its Lua-visible name) without the macro having to
+/// invent its own attribute syntax for every knob. Problems are not
+/// reported: an unknown bare key is skipped, and an unknown `key = value` or
+/// a malformed value ends parsing of that attribute, keeping the options
+/// read before it.
+#[derive(Default)]
+struct MluaMagicOpts {
+	readonly: Option<bool>,
+	rename: Option<String>,
+	rename_all: Option<String>,
+}
+
+/// Scans `attrs` for `#[mlua_magic(...)]` and collects the options found.
+fn parse_mlua_magic_opts(attrs: &[syn::Attribute]) -> MluaMagicOpts {
+	let mut opts = MluaMagicOpts::default();
+
+	for attr in attrs {
+		if !attr.path().is_ident("mlua_magic") {
+			continue;
+		};
+
+		let _ = attr.parse_nested_meta(|meta| {
+			if meta.path.is_ident("readonly") {
+				opts.readonly = Some(true);
+			} else if meta.path.is_ident("writable") {
+				opts.readonly = Some(false);
+			} else if meta.path.is_ident("rename") {
+				let value: syn::LitStr = meta.value()?.parse()?;
+				opts.rename = Some(value.value());
+			} else if meta.path.is_ident("rename_all") {
+				let value: syn::LitStr = meta.value()?.parse()?;
+				opts.rename_all = Some(value.value());
+			};
+
+			Ok(())
+		});
+	}
+
+	return opts;
+}
+
+/// Resolves the Lua-visible name for an item: an explicit `rename` wins,
+/// otherwise `rename_all` (if any) is applied to the Rust identifier,
+/// otherwise the identifier is used unchanged.
+fn resolve_lua_name(rust_name: &str, item_opts: &MluaMagicOpts, rename_all: Option<&str>) -> String {
+	if let Some(rename) = &item_opts.rename {
+		return rename.clone();
+	};
+
+	match rename_all {
+		Some(style) => case::convert_case(rust_name, style),
+		None => rust_name.to_string(),
+	}
+}
+
+/// Strips `#[mlua_magic(...)]` attributes so they don't leak into the
+/// generated output as unrecognized attributes.
+fn strip_mlua_magic_attrs(attrs: &mut Vec<syn::Attribute>) {
+	attrs.retain(|attr| !attr.path().is_ident("mlua_magic"));
+}
+
 /// Implements a helper function `_to_mlua_fields` for a Rust struct,
 /// enabling automatic registration of named fields with `mlua::UserData`.
 ///
@@ -25,13 +93,29 @@ use ::syn::{
 /// are visible in Lua as userdata fields.
 ///
 /// # Behavior
-/// * Public and private named fields are exported as readable fields in Lua.
-/// * Getter methods are automatically generated via `add_field_method_get`.
+/// * Public and private named fields are exported as readable fields in Lua,
+///   via a getter generated with `add_field_method_get`.
+/// * Fields are also writable from Lua by default, via a setter generated
+///   with `add_field_method_set` — so `player.hp = 50` works. Mark an
+///   individual field `#[mlua_magic(readonly)]` to skip generating its
+///   setter (e.g. an `hp` you only want mutated through `take_damage`), or
+///   put `#[mlua_magic(readonly)]` on the struct itself to make every field
+///   read-only by default and opt individual fields back in with
+///   `#[mlua_magic(writable)]`.
 /// * Fields must implement `Clone` for successful conversion to Lua values.
+/// * A field's Lua-visible name matches its Rust identifier by default. Put
+///   `#[mlua_magic(rename_all = "camelCase")]` on the struct to transform
+///   every field name with a case converter (`camelCase`, `PascalCase`,
+///   `snake_case`, `SCREAMING_SNAKE_CASE`, `kebab-case`), or
+///   `#[mlua_magic(rename = "...")]` on an individual field to override it
+///   directly. The Rust identifier itself is never touched.
+/// * Also generates a `mlua::FromLua` impl (requires `Self: Clone`), so a
+///   userdata instance mutated by a Lua script can be pulled back into Rust
+///   with `lua.globals().get::<Player>("player")?` — the same round-trip
+///   `#[enumeration]` already supports for enums.
 ///
 /// # Limitations
 /// * Only structs with **named fields** are currently supported.
-/// * Setter support is not yet implemented.
 ///
 /// # Usage
 /// Apply the macro directly to the struct definition:
@@ -65,8 +149,9 @@ use ::syn::{
 /// ensuring a consistent interface between Rust types and Lua scripts.
 #[proc_macro_attribute]
 pub fn structure(_attr: TokenStream, item: TokenStream) -> TokenStream {
-	let ast: syn::ItemStruct = parse_macro_input!(item as syn::ItemStruct);
+	let mut ast: syn::ItemStruct = parse_macro_input!(item as syn::ItemStruct);
 	let name: &Ident = &ast.ident;
+	let name_str: String = name.to_string();
 
 	/*let fields = match &ast.fields {
 		Data::Struct(DataStruct {
@@ -77,12 +162,25 @@ pub fn structure(_attr: TokenStream, item: TokenStream) -> TokenStream {
 	};*/
 	// ^^^^
 	// TODO: Add type validation?
+
+	// A `#[mlua_magic(readonly)]` on the struct itself flips the default for
+	// every field; individual fields can still override it either way.
+	// `rename_all` similarly sets the default case style for every field's
+	// Lua-visible name, overridable per field with `rename`.
+	let type_opts: MluaMagicOpts = parse_mlua_magic_opts(&ast.attrs);
+	let default_readonly: bool = type_opts.readonly.unwrap_or(false);
+	let rename_all: Option<String> = type_opts.rename_all;
+	strip_mlua_magic_attrs(&mut ast.attrs);
+
 	let mut user_data_fields = Vec::new();
 
-	for field in &ast.fields {
+	for field in ast.fields.iter_mut() {
+		let field_opts: MluaMagicOpts = parse_mlua_magic_opts(&field.attrs);
+		strip_mlua_magic_attrs(&mut field.attrs);
+
 		let field_name: &Ident = field.ident.as_ref().expect("Field must have a name");
-		let field_name_str: String = field_name.to_string();
-		// let field_ty: &syn::Type = &field.ty;
+		let field_name_str: String = resolve_lua_name(&field_name.to_string(), &field_opts, rename_all.as_deref());
+		let field_ty: &syn::Type = &field.ty;
 
 		user_data_fields.push(quote! {
 			fields.add_field_method_get(#field_name_str, |_, this| {
@@ -90,15 +188,20 @@ pub fn structure(_attr: TokenStream, item: TokenStream) -> TokenStream {
 			});
 		});
 
-		/*user_data_fields.push(quote! {
-			fields.add_field_method_set(#field_name_str, |_, this, val: #field_ty| {
-				this.#field_name = val;
-				return Ok(());
+		let is_readonly: bool = field_opts.readonly.unwrap_or(default_readonly);
+		if !is_readonly {
+			user_data_fields.push(quote! {
+				fields.add_field_method_set(#field_name_str, |_, this, val: #field_ty| {
+					this.#field_name = val;
+					return Ok(());
+				});
 			});
-		});*/
+		};
 	}
 
-	// Create the helper function `_to_mlua_fields`
+	// Create the helper function `_to_mlua_fields`, plus a `FromLua` impl so
+	// mutations made to a userdata instance inside Lua can be pulled back into
+	// Rust (mirrors the one `#[enumeration]` generates for enum variants).
 	let helper_fn: proc_macro2::TokenStream = quote! {
 		impl #name {
 			#[doc(hidden)]
@@ -106,6 +209,23 @@ pub fn structure(_attr: TokenStream, item: TokenStream) -> TokenStream {
 				#(#user_data_fields)*
 			}
 		}
+
+		impl mlua::FromLua for #name where #name: Clone {
+			fn from_lua(value: mlua::Value, _: &mlua::Lua) -> mlua::Result<Self> {
+				match value {
+					mlua::Value::UserData(ud) => {
+						// Attempt to borrow the inner struct; clone and return owned value.
+						let borrowed = ud.borrow::<#name>()?;
+						Ok(borrowed.clone())
+					},
+					other => Err(mlua::Error::FromLuaConversionError {
+						from: other.type_name(),
+						to: #name_str.to_string(),
+						message: Some(format!("expected userdata for {}", stringify!(#name))),
+					})
+				}
+			}
+		}
 	};
 
 	let original_tokens: proc_macro2::TokenStream = quote! { #ast };
@@ -119,14 +239,18 @@ pub fn structure(_attr: TokenStream, item: TokenStream) -> TokenStream {
 
 /// Implements a helper function `_to_mlua_variants` for an enum.
 ///
-/// This function registers all *unit variants* (e.g., `MyEnum::VariantA`)
-/// as static properties on the Lua UserData. This allows accessing
-/// them in Lua as `MyEnum.VariantA`.
+/// This function registers every variant as a static constructor function
+/// on the Lua UserData:
+/// * Unit variants (e.g., `MyEnum::VariantA`) take no arguments, so Lua can
+///   access them directly as `MyEnum.VariantA()`.
+/// * Tuple/struct variants (e.g., `MyEnum::VariantB(i32)`) take the
+///   variant's payload as typed Lua arguments, so Lua can call
+///   `MyEnum.VariantB(42)`.
 ///
-/// Variants with data (e.g., `MyEnum::VariantB(i32)`) are *not*
-/// automatically registered. You should expose these by creating
-/// a static constructor function in an `#[mlua_magic::implementation]`
-/// block.
+/// It also registers a `:kind()` method returning the variant name as a
+/// string (so Lua code can branch on which variant a value holds), and, for
+/// variants with exactly one field, a `:value()` method returning that
+/// field's value.
 ///
 /// # Example:
 /// ```ignore
@@ -136,40 +260,150 @@ pub fn structure(_attr: TokenStream, item: TokenStream) -> TokenStream {
 ///	 VariantA,
 ///	 VariantB(i32),
 /// }
-///
-/// #[mlua_magic::implementation]
-/// impl MyEnum {
-///	 // This will expose `MyEnum.new_variant_b(123)` in Lua
-///	 pub fn new_variant_b(val: i32) -> Self {
-///		 Self::VariantB(val)
-///	 }
-/// }
+/// ```
+/// ```lua
+/// local a = MyEnum.VariantA();
+/// local b = MyEnum.VariantB(42);
+/// print(b:kind());  -- "VariantB"
+/// print(b:value()); -- 42
 /// ```
 ///
+/// A variant's Lua-visible name matches its Rust identifier by default; put
+/// `#[mlua_magic(rename_all = "camelCase")]` on the enum to transform every
+/// variant name, or `#[mlua_magic(rename = "...")]` on an individual variant
+/// to override it directly.
+///
 /// This is intended to be used with `impl mlua::UserData`.
 #[proc_macro_attribute]
 pub fn enumeration(_attr: TokenStream, item: TokenStream) -> TokenStream {
-	let ast: ItemEnum = parse_macro_input!(item as ItemEnum);
+	let mut ast: ItemEnum = parse_macro_input!(item as ItemEnum);
 	let name: &Ident = &ast.ident;
 	let name_str: String = name.to_string();
 
-	// Build registrations for unit variants (register as static constructors)
+	// `rename_all` on the enum sets the default case style for every
+	// variant's Lua-visible name; a variant can override it with `rename`.
+	let rename_all: Option<String> = parse_mlua_magic_opts(&ast.attrs).rename_all;
+	strip_mlua_magic_attrs(&mut ast.attrs);
+
+	// Build registrations for each variant: unit variants become zero-arg
+	// static constructors; tuple/struct variants become constructors that
+	// take the variant's payload as typed Lua arguments.
+	let total_variants: usize = ast.variants.len();
+
 	let mut variant_registrations: Vec<proc_macro2::TokenStream> = Vec::new();
-	for variant in &ast.variants {
-		if let Fields::Unit = &variant.fields {
-			let variant_name: &Ident = &variant.ident;
-			let variant_name_str: String = variant_name.to_string();
-
-			// use add_function to register an associated/static function that returns the enum
-			variant_registrations.push(quote! {
-				// e.g. methods.add_function("Idle", |_, (): ()| Ok(PlayerStatus::Idle));
-				methods.add_function(#variant_name_str, |_, (): ()| {
-					Ok(#name::#variant_name)
+	// `kind()` match arms: every variant maps to its name, regardless of fields.
+	let mut kind_arms: Vec<proc_macro2::TokenStream> = Vec::new();
+	// `value()` match arms: only single-field variants have an unambiguous inner value.
+	let mut value_arms: Vec<proc_macro2::TokenStream> = Vec::new();
+	let mut has_value_arms: bool = false;
+
+	for variant in ast.variants.iter_mut() {
+		let variant_opts: MluaMagicOpts = parse_mlua_magic_opts(&variant.attrs);
+		strip_mlua_magic_attrs(&mut variant.attrs);
+
+		let variant_name: &Ident = &variant.ident;
+		let variant_name_str: String = resolve_lua_name(&variant_name.to_string(), &variant_opts, rename_all.as_deref());
+
+		match &variant.fields {
+			Fields::Unit => {
+				// use add_function to register an associated/static function that returns the enum
+				variant_registrations.push(quote! {
+					// e.g. methods.add_function("Idle", |_, (): ()| Ok(PlayerStatus::Idle));
+					methods.add_function(#variant_name_str, |_, (): ()| {
+						Ok(#name::#variant_name)
+					});
 				});
-			});
-		}
+
+				kind_arms.push(quote! {
+					#name::#variant_name => #variant_name_str,
+				});
+			},
+			Fields::Unnamed(fields) => {
+				let arg_names: Vec<Ident> = (0..fields.unnamed.len())
+					.map(|i| format_ident!("arg{}", i))
+					.collect();
+				let arg_tys: Vec<&syn::Type> = fields.unnamed.iter().map(|field| &field.ty).collect();
+
+				// e.g. Status.Busy(42) -> methods.add_function("Busy", |_, (arg0,): (i32,)| Ok(Status::Busy(arg0)));
+				variant_registrations.push(quote! {
+					methods.add_function(#variant_name_str, |_, (#(#arg_names,)*): (#(#arg_tys,)*)| {
+						Ok(#name::#variant_name(#(#arg_names,)*))
+					});
+				});
+
+				kind_arms.push(quote! {
+					#name::#variant_name(..) => #variant_name_str,
+				});
+
+				if let [only_arg] = arg_names.as_slice() {
+					value_arms.push(quote! {
+						#name::#variant_name(#only_arg) => mlua::IntoLua::into_lua(#only_arg.clone(), lua),
+					});
+					has_value_arms = true;
+				}
+			},
+			Fields::Named(fields) => {
+				let arg_names: Vec<&Ident> = fields.named.iter()
+					.map(|field| field.ident.as_ref().expect("Named field must have a name"))
+					.collect();
+				let arg_tys: Vec<&syn::Type> = fields.named.iter().map(|field| &field.ty).collect();
+
+				// e.g. Status.Targeting { entity_id: 7 } -> methods.add_function("Targeting", |_, (entity_id,): (u32,)| Ok(Status::Targeting { entity_id }));
+				variant_registrations.push(quote! {
+					methods.add_function(#variant_name_str, |_, (#(#arg_names,)*): (#(#arg_tys,)*)| {
+						Ok(#name::#variant_name { #(#arg_names),* })
+					});
+				});
+
+				kind_arms.push(quote! {
+					#name::#variant_name { .. } => #variant_name_str,
+				});
+
+				if let [only_arg] = arg_names.as_slice() {
+					value_arms.push(quote! {
+						#name::#variant_name { #only_arg } => mlua::IntoLua::into_lua(#only_arg.clone(), lua),
+					});
+					has_value_arms = true;
+				}
+			},
+		};
 	}
 
+	// `:kind()` lets Lua branch on which variant a value holds, even for
+	// tuple/struct variants that can't be compared with `==`.
+	variant_registrations.push(quote! {
+		methods.add_method("kind", |_, this, ()| {
+			Ok(match this {
+				#(#kind_arms)*
+			}.to_string())
+		});
+	});
+
+	// `:value()` only makes sense for single-field variants, since the
+	// returned Lua type would otherwise be ambiguous across variants.
+	//
+	// The wildcard fallback arm is only emitted when some variant lacks a
+	// value arm (e.g. a unit or multi-field variant) — if every variant is
+	// single-field, `value_arms` is already exhaustive and adding `_ => ...`
+	// would trip `unreachable_patterns` under `-D warnings`.
+	if has_value_arms {
+		let needs_fallback: bool = value_arms.len() < total_variants;
+		let fallback_arm = if needs_fallback {
+			quote! { _ => Ok(mlua::Value::Nil), }
+		} else {
+			quote! {}
+		};
+
+		variant_registrations.push(quote! {
+			methods.add_method("value", |lua, this, ()| {
+				match this {
+					#(#value_arms)*
+					#fallback_arm
+				}
+			});
+		});
+	};
+
 	// Create helper fn _to_mlua_variants, plus FromLua and IntoLua impls for lossless userdata round-trip.
 	// FromLua requires Clone so we can return owned values from borrowed userdata.
 	let helper_fn: proc_macro2::TokenStream = quote! {
@@ -209,21 +443,137 @@ pub fn enumeration(_attr: TokenStream, item: TokenStream) -> TokenStream {
 }
 
 
+/// Implements a helper function `_to_mlua_metamethods` that wires up Lua
+/// metamethods from standard Rust traits the type already implements.
+///
+/// Since a proc-macro attribute can't see trait impls, you tell it which
+/// metamethods to generate by naming the trait-backed capability directly:
+///
+/// ```ignore
+/// #[derive(Serialize, PartialEq)]
+/// #[mlua_magic_macros::metamethods(to_string, eq)]
+/// #[mlua_magic_macros::structure]
+/// struct Player { name: String, hp: i32 }
+/// ```
+///
+/// * `to_string` — requires `Serialize`; registers `MetaMethod::ToString` so
+///   `print(player)` / `tostring(player)` serialize the value to a compact
+///   JSON string. The generated code calls `serde_json::to_string`, so the
+///   consuming crate must depend on `serde_json` directly (it is not
+///   re-exported by this crate).
+/// * `eq` — requires `PartialEq`; registers `MetaMethod::Eq`.
+/// * `lt` / `le` — require `PartialOrd`; register `MetaMethod::Lt` / `Le`.
+///
+/// Pair this with `compile!(Player, fields, methods, meta)` — the `meta`
+/// helper hooks the generated `_to_mlua_metamethods` into `add_methods`.
+#[proc_macro_attribute]
+pub fn metamethods(attr: TokenStream, item: TokenStream) -> TokenStream {
+	let requested: Punctuated<Ident, Token![,]> = parse_macro_input!(attr with Punctuated::parse_terminated);
+
+	let ast: DeriveInput = parse_macro_input!(item as DeriveInput);
+	let name: &Ident = &ast.ident;
+
+	let mut metamethod_registrations: Vec<proc_macro2::TokenStream> = Vec::new();
+
+	for capability in &requested {
+		let registration: proc_macro2::TokenStream = match capability.to_string().as_str() {
+			"to_string" => quote! {
+				methods.add_meta_method(mlua::MetaMethod::ToString, |_, this, ()| {
+					serde_json::to_string(this).map_err(mlua::Error::external)
+				});
+			},
+			"eq" => quote! {
+				methods.add_meta_method(mlua::MetaMethod::Eq, |_, this, other: mlua::AnyUserData| {
+					let other = other.borrow::<Self>()?;
+					Ok(this == &*other)
+				});
+			},
+			"lt" => quote! {
+				methods.add_meta_method(mlua::MetaMethod::Lt, |_, this, other: mlua::AnyUserData| {
+					let other = other.borrow::<Self>()?;
+					Ok(this < &*other)
+				});
+			},
+			"le" => quote! {
+				methods.add_meta_method(mlua::MetaMethod::Le, |_, this, other: mlua::AnyUserData| {
+					let other = other.borrow::<Self>()?;
+					Ok(this <= &*other)
+				});
+			},
+			other => return syn::Error::new(capability.span(), format!("Unknown metamethod: {}", other))
+				.to_compile_error()
+				.into(),
+		};
+
+		metamethod_registrations.push(registration);
+	};
+
+	// Create the helper function `_to_mlua_metamethods`
+	let helper_fn: proc_macro2::TokenStream = quote! {
+		impl #name {
+			#[doc(hidden)]
+			pub fn _to_mlua_metamethods<M: mlua::UserDataMethods<Self>>(methods: &mut M) -> () {
+				#(#metamethod_registrations)*
+			}
+		}
+	};
+
+	let original_tokens: proc_macro2::TokenStream = quote! { #ast };
+	let helper_tokens: proc_macro2::TokenStream = quote! { #helper_fn };
+
+	let mut output: proc_macro2::TokenStream = original_tokens;
+	output.extend(helper_tokens);
+
+	return output.into();
+}
+
+
 /// Generates a helper function `_to_mlua_methods` for an `impl` block.
 ///
 /// This function registers all methods in the `impl` block with mlua,
 /// correctly distinguishing between static, `&self`, and `&mut self` methods.
+///
+/// `async fn` methods are registered with mlua's async counterparts
+/// (`add_async_method`, `add_async_method_mut`, `add_async_function`) instead,
+/// so `player:fetch_remote_data():await()`-style coroutines work from Lua.
+/// The `#[cfg(feature = "async")]` / `#[cfg(not(feature = "async"))]` guard
+/// emitted around these registrations is evaluated in the *consuming* crate,
+/// so the consumer — not this crate — must declare its own `async` feature
+/// (which in turn should enable mlua's `async` feature) in `Cargo.toml`
+/// before writing an `async fn` method; without it, the `compile_error!`
+/// branch fires instead of silently producing code that won't build.
+///
+/// Static/constructor methods (those with no receiver) are additionally
+/// collected for `compile!`'s generated `register_globals`, which installs
+/// them onto a Lua table exposed as a global named after the type.
+///
+/// A method's Lua-visible name matches its Rust identifier by default. Put
+/// `#[mlua_magic(rename_all = "camelCase")]` on the `impl` block to transform
+/// every method name with a case converter, or `#[mlua_magic(rename = "...")]`
+/// on an individual method to override it directly — so Rust code can keep
+/// idiomatic `take_damage` while Lua scripts call `player:takeDamage(30)`.
 #[proc_macro_attribute]
 pub fn implementation(_attr: TokenStream, item: TokenStream) -> TokenStream {
-	let ast: syn::ItemImpl = parse_macro_input!(item as syn::ItemImpl);
+	let mut ast: syn::ItemImpl = parse_macro_input!(item as syn::ItemImpl);
 	let name: &Box<syn::Type> = &ast.self_ty;
 
+	// `rename_all` on the `impl` block sets the default case style for every
+	// method's Lua-visible name; a method can override it with `rename`.
+	let rename_all: Option<String> = parse_mlua_magic_opts(&ast.attrs).rename_all;
+	strip_mlua_magic_attrs(&mut ast.attrs);
+
 	let mut method_registrations: Vec<proc_macro2::TokenStream> = Vec::new();
+	// Static/constructor functions (no receiver) are also collected here so
+	// `compile!` can install them onto a Lua table and expose it as a global.
+	let mut static_registrations: Vec<proc_macro2::TokenStream> = Vec::new();
 
-	for item in &ast.items {
+	for item in ast.items.iter_mut() {
 		if let ImplItem::Fn(fn_item) = item {
+			let fn_opts: MluaMagicOpts = parse_mlua_magic_opts(&fn_item.attrs);
+			strip_mlua_magic_attrs(&mut fn_item.attrs);
+
 			let fn_name: &Ident = &fn_item.sig.ident;
-			let fn_name_str: String = fn_name.to_string();
+			let fn_name_str: String = resolve_lua_name(&fn_name.to_string(), &fn_opts, rename_all.as_deref());
 
 			// Extract argument names and types, skipping the `self` receiver
 			let (arg_names, arg_tys): (Vec<_>, Vec<_>) = fn_item
@@ -243,6 +593,59 @@ pub fn implementation(_attr: TokenStream, item: TokenStream) -> TokenStream {
 				})
 				.unzip();
 
+			// `async fn` methods need mlua's async registration functions instead of the
+			// synchronous ones, and are only available when the consumer has pulled in
+			// mlua's own `async` feature. The generated branch is gated behind an
+			// `async` feature that the `cfg` checks in the consuming crate; without it,
+			// `compile_error!` names the method instead of the build failing on
+			// missing mlua functions.
+			if fn_item.sig.asyncness.is_some() {
+				if let Some(receiver) = &fn_item.sig.receiver() {
+					if receiver.mutability.is_some() {
+						// `&mut self`, async — mlua hands us an owned `UserDataRefMut`
+						// guard (not a bare `&mut T`), so the binding itself must be
+						// `mut` for `this.#fn_name(...)` to borrow it mutably.
+						method_registrations.push(quote! {
+							#[cfg(feature = "async")]
+							methods.add_async_method_mut(#fn_name_str, |_, mut this, (#(#arg_names,)*): (#(#arg_tys,)*)| async move {
+								Ok(this.#fn_name(#(#arg_names,)*).await)
+							});
+							#[cfg(not(feature = "async"))]
+							compile_error!(concat!("async fn ", #fn_name_str, " requires the \"async\" feature"));
+						}.into());
+					} else {
+						// `&self`, async — mlua's newer signature borrows `T` rather than cloning it
+						method_registrations.push(quote! {
+							#[cfg(feature = "async")]
+							methods.add_async_method(#fn_name_str, |_, this, (#(#arg_names,)*): (#(#arg_tys,)*)| async move {
+								Ok(this.#fn_name(#(#arg_names,)*).await)
+							});
+							#[cfg(not(feature = "async"))]
+							compile_error!(concat!("async fn ", #fn_name_str, " requires the \"async\" feature"));
+						}.into());
+					};
+				} else {
+					// Static async function (e.g. an async constructor)
+					method_registrations.push(quote! {
+						#[cfg(feature = "async")]
+						methods.add_async_function(#fn_name_str, |_, (#(#arg_names,)*): (#(#arg_tys,)*)| async move {
+							Ok(#name::#fn_name(#(#arg_names,)*).await)
+						});
+						#[cfg(not(feature = "async"))]
+						compile_error!(concat!("async fn ", #fn_name_str, " requires the \"async\" feature"));
+					}.into());
+
+					static_registrations.push(quote! {
+						#[cfg(feature = "async")]
+						table.set(#fn_name_str, lua.create_async_function(|_, (#(#arg_names,)*): (#(#arg_tys,)*)| async move {
+							Ok(#name::#fn_name(#(#arg_names,)*).await)
+						})?)?;
+					}.into());
+				};
+
+				continue;
+			}
+
 			// Check for `&self`, `&mut self`, or static
 			if let Some(receiver) = &fn_item.sig.receiver() {
 				if receiver.mutability.is_some() {
@@ -267,17 +670,31 @@ pub fn implementation(_attr: TokenStream, item: TokenStream) -> TokenStream {
 						Ok(#name::#fn_name(#(#arg_names,)*))
 					});
 				}.into());
+
+				static_registrations.push(quote! {
+					table.set(#fn_name_str, lua.create_function(|_, (#(#arg_names,)*): (#(#arg_tys,)*)| {
+						Ok(#name::#fn_name(#(#arg_names,)*))
+					})?)?;
+				}.into());
 			};
 		};
 	};
 
-	// Create the helper function `_to_mlua_methods`
+	// Create the helper functions `_to_mlua_methods` and `_to_mlua_statics`.
+	// The latter is consumed by `compile!`'s generated `register_globals`, to
+	// install every static/constructor method onto a Lua table.
 	let helper_fn: proc_macro2::TokenStream = quote! {
 		impl #name {
 			#[doc(hidden)]
 			pub fn _to_mlua_methods<M: mlua::UserDataMethods<Self>>(methods: &mut M) -> () {
 				#(#method_registrations)*
 			}
+
+			#[doc(hidden)]
+			pub fn _to_mlua_statics(lua: &mlua::Lua, table: &mlua::Table) -> mlua::Result<()> {
+				#(#static_registrations)*
+				Ok(())
+			}
 		}
 	};
 
@@ -298,9 +715,11 @@ pub fn implementation(_attr: TokenStream, item: TokenStream) -> TokenStream {
 /// Generates the final `impl mlua::UserData` block for a type.
 ///
 /// This macro calls the helper functions generated by `#[structure]`,
-/// `#[implementation]`, and `#[enumeration]`.
+/// `#[implementation]`, `#[enumeration]`, and `#[metamethods]`.
 ///
-/// You must specify which helpers to include.
+/// You must specify which helpers to include: `fields`, `methods`,
+/// `variants`, and/or `meta` (the last requires `#[metamethods(...)]` on the
+/// type).
 ///
 /// # Example (for a struct):
 /// ```ignore
@@ -312,8 +731,12 @@ pub fn implementation(_attr: TokenStream, item: TokenStream) -> TokenStream {
 ///	 // ... methods ...
 /// }
 ///
-/// // Generates `impl mlua::UserData for Player`
+/// // Generates `impl mlua::UserData for Player`, plus `Player::register_globals`
 /// mlua_magic::compile!(Player, fields, methods);
+///
+/// // Installs every static method (e.g. `new`) as a global Lua table, with
+/// // `new` doubling as the table's `__call` so `Player("LuaHero")` works too.
+/// Player::register_globals(&lua)?;
 /// ```
 ///
 /// # Example (for an enum):
@@ -336,19 +759,22 @@ pub fn compile(item: TokenStream) -> TokenStream {
 	let mut has_fields: bool = false;
 	let mut has_methods: bool = false;
 	let mut has_variants: bool = false;
+	let mut has_meta: bool = false;
 
 	// Check which helpers the user specified
 	for helper in helpers {
 		let h: String = helper.to_string();
-		if h == "fields" { 
-			has_fields = true; 
-		} else if h == "methods" { 
-			has_methods = true; 
-		} else if h == "variants" { 
-			has_variants = true; 
+		if h == "fields" {
+			has_fields = true;
+		} else if h == "methods" {
+			has_methods = true;
+		} else if h == "variants" {
+			has_variants = true;
+		} else if h == "meta" {
+			has_meta = true;
 		} else {
 			// Return a compile error if the helper name is unknown
-			return syn::Error::new(helper.span(), "Unknown helper: expected 'fields', 'methods', or 'variants'")
+			return syn::Error::new(helper.span(), "Unknown helper: expected 'fields', 'methods', 'variants', or 'meta'")
 				.to_compile_error()
 				.into();
 		};
@@ -379,6 +805,43 @@ pub fn compile(item: TokenStream) -> TokenStream {
 		quote! { /* Do nothing */ }
 	};
 
+	let meta_call = if has_meta {
+		quote! {
+			Self::_to_mlua_metamethods(methods);
+		}
+	} else {
+		quote! { /* Do nothing */ }
+	};
+
+	// When `methods` is requested, also expose every static/constructor method
+	// discovered by `#[implementation]` as a Lua global table named after the
+	// type — so `Player("LuaHero")` and `Player.new("LuaHero")` both work
+	// without the caller hand-writing `lua.globals().set(...)`. If a `new`
+	// static was found, it doubles as the table's `__call` metamethod.
+	let register_globals_impl = if has_methods {
+		quote! {
+			impl #type_name {
+				pub fn register_globals(lua: &mlua::Lua) -> mlua::Result<()> {
+					let table = lua.create_table()?;
+					Self::_to_mlua_statics(lua, &table)?;
+
+					if let Some(new_fn) = table.get::<Option<mlua::Function>>("new")? {
+						let metatable = lua.create_table()?;
+						metatable.set("__call", lua.create_function(move |_, (_table, args): (mlua::Table, mlua::MultiValue)| {
+							new_fn.call::<mlua::Value>(args)
+						})?)?;
+						table.set_metatable(Some(metatable));
+					};
+
+					lua.globals().set(stringify!(#type_name), table)?;
+					Ok(())
+				}
+			}
+		}
+	} else {
+		quote! { /* Do nothing */ }
+	};
+
 	// Assemble the final `impl mlua::UserData` block
 	let output: proc_macro2::TokenStream = quote! {
 		impl mlua::UserData for #type_name {
@@ -389,8 +852,12 @@ pub fn compile(item: TokenStream) -> TokenStream {
 			fn add_methods<'lua, M: mlua::UserDataMethods<Self>>(methods: &mut M) -> () {
 				#methods_call
 				#variants_call
+				#meta_call
 			}
 		}
+
+		#register_globals_impl
+
 		/*impl mlua::IntoLua for #type_name {
 			fn into_lua(self, lua: &mlua::Lua) -> mlua::Result<mlua::Value> {
 				let user_data: mlua::AnyUserData = lua.create_any_userdata(self)?;
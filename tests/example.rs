@@ -12,11 +12,13 @@ pub mod example {
 	#[derive(Clone)]
 	#[derive(Copy)]
 	#[derive(Debug)]
+	#[derive(PartialEq)]
 	#[mlua_magic_macros::enumeration]
 	pub enum PlayerStatus {
 		Idle,
 		Walking,
 		Attacking,
+		Stunned(u32), // carries the number of seconds remaining
 	}
 	
 	mlua_magic_macros::compile!(PlayerStatus, variants);
@@ -24,6 +26,7 @@ pub mod example {
 	#[derive(Serialize)]
 	#[derive(Clone)]
 	#[derive(Debug)]
+	#[mlua_magic_macros::metamethods(to_string)]
 	#[mlua_magic_macros::structure]
 	pub struct Player {
 		name: String,
@@ -32,6 +35,7 @@ pub mod example {
 	}
 
 	#[mlua_magic_macros::implementation]
+	#[mlua_magic(rename_all = "camelCase")]
 	impl Player {
 		// This will be registered as a static "constructor"
 		pub fn new(name: String) -> Self {
@@ -58,7 +62,42 @@ pub mod example {
 		}
 	}
 
-	mlua_magic_macros::compile!(Player, fields, methods);
+	mlua_magic_macros::compile!(Player, fields, methods, meta);
+
+	// Exercises the three async registration paths (`add_async_method`,
+	// `add_async_method_mut`, `add_async_function`). Kept as its own type,
+	// gated behind this crate's "async" feature, so the default (sync) build
+	// and the `main` test above aren't forced to enable it just to compile.
+	#[cfg(feature = "async")]
+	#[derive(Clone, Debug)]
+	#[mlua_magic_macros::structure]
+	pub struct AsyncPlayer {
+		name: String,
+		hp: i32,
+	}
+
+	#[cfg(feature = "async")]
+	#[mlua_magic_macros::implementation]
+	#[mlua_magic(rename_all = "camelCase")]
+	impl AsyncPlayer {
+		// Async static "constructor" -> `methods.add_async_function`
+		pub async fn new_async(name: String) -> Self {
+			return Self { name: name, hp: 100 };
+		}
+
+		// Async `&self` method -> `methods.add_async_method`
+		pub async fn greet_async(&self) -> String {
+			return format!("Hello, {}", self.name);
+		}
+
+		// Async `&mut self` method -> `methods.add_async_method_mut`
+		pub async fn heal_async(&mut self, amount: i32) -> () {
+			self.hp += amount;
+		}
+	}
+
+	#[cfg(feature = "async")]
+	mlua_magic_macros::compile!(AsyncPlayer, fields, methods);
 
 	#[test]
 	fn main() -> LuaResult<()> {
@@ -66,15 +105,17 @@ pub mod example {
 		let lua = Lua::new();
 
 		// --- We can now call Player.new() FROM LUA! ---
-		// We must register the type "constructor" with Lua first
-		lua.globals().set("Player", lua.create_function(|_: & Lua, name: String| {
-			return Ok(Player::new(name));
-		})?)?;
+		// `register_globals` installs every static/constructor method (here,
+		// just `new`) onto a `Player` global table, with `new` doubling as
+		// the table's `__call` so `Player("LuaHero")` works too.
+		Player::register_globals(&lua)?;
 
 		// --- This is the Lua script we will run ---
+		// `player` is left as a global (not `local`) so we can read it back
+		// into Rust once the script has finished running.
 		let lua_script: &str = r#"
 			-- Call the static `new` function we registered
-			local player = Player("LuaHero");
+			player = Player("LuaHero");
 			print("Player created:");
 			print(player);
 
@@ -82,35 +123,80 @@ pub mod example {
 			print("Player name:", player.name);
 			print("Player HP:", player.hp);
 			print("Player status:", player.status);
-			print("Is alive?", player:is_alive());
+			print("Is alive?", player:isAlive());
 
 			-- Call our new custom method
-			player:take_damage(30);
-			
+			player:takeDamage(30);
+
 			print("-----------------------------------")
 			print("New player HP:", player.hp)
 
+			-- Fields are writable from Lua too, not just through methods
+			player.status = player.status.Attacking();
+			print("Player status after direct field set:", player.status);
+
+			-- Variants carrying data get a constructor, plus :kind()/:value()
+			local stunned = player.status.Stunned(3);
+			print("Stunned status kind:", stunned:kind());
+			print("Stunned seconds remaining:", stunned:value());
+
 			-- Call the method again
-			player:take_damage(80);
+			player:takeDamage(80);
 			print("Player HP after final hit:", player.hp);
-			print("Is alive?", player:is_alive());
+			print("Is alive?", player:isAlive());
 		"#;
 
 		// Execute the script
 		lua.load(lua_script).exec()?;
 
 		// We can also retrieve the player and see the changes reflected in Rust
-		// let modified_player: Player = lua.globals().get("player")?;
+		let modified_player: Player = lua.globals().get("player")?;
 
 		info!("\n--- Back in Rust ---");
-		// println!("Player after Lua script: {:?}", modified_player);
+		println!("Player after Lua script: {:?}", modified_player);
 
-		// assert_eq!(modified_player.hp, 0);
-		// assert_eq!(modified_player.status, PlayerStatus::Attacking);
-		// assert_eq!(modified_player.is_alive(), false);
+		assert_eq!(modified_player.hp, 0);
+		assert_eq!(modified_player.status, PlayerStatus::Attacking);
+		assert_eq!(modified_player.is_alive(), false);
 
 		Ok(())
 	}
 
+	// Exercises `add_async_method`, `add_async_method_mut`, and
+	// `add_async_function` end to end, so the async registration paths in
+	// `#[implementation]` don't ship unverified. Only compiled/run when this
+	// crate's "async" feature (and therefore mlua's) is enabled.
+	#[cfg(feature = "async")]
+	#[tokio::test]
+	async fn async_methods() -> LuaResult<()> {
+		let lua = Lua::new();
+		AsyncPlayer::register_globals(&lua)?;
+
+		let player = lua.create_userdata(AsyncPlayer {
+			name: "AsyncHero".to_string(),
+			hp: 100,
+		})?;
+
+		// `&self` async method
+		let greeting: String = player.call_async_method("greetAsync", ()).await?;
+		assert_eq!(greeting, "Hello, AsyncHero");
+
+		// `&mut self` async method. There's no `call_async_method_mut` on
+		// `AnyUserData` — both paths go through `call_async_method` — and the
+		// return value is discarded, so it needs an explicit type annotation
+		// to avoid relying on never-type fallback.
+		let _: () = player.call_async_method("healAsync", (20,)).await?;
+		let hp: i32 = player.get("hp")?;
+		assert_eq!(hp, 120);
+
+		// Static (no receiver) async function, reached through the Lua
+		// global table `register_globals` installs
+		let player_table: LuaTable = lua.globals().get("AsyncPlayer")?;
+		let new_async: LuaFunction = player_table.get("newAsync")?;
+		let sidekick: AsyncPlayer = new_async.call_async(("AsyncSidekick".to_string(),)).await?;
+		assert_eq!(sidekick.name, "AsyncSidekick");
+
+		Ok(())
+	}
 
 }
\ No newline at end of file